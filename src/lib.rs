@@ -11,9 +11,11 @@
 
 mod unreal;
 
+use arrayvec::{ArrayString, ArrayVec};
 use asr::{
     future::{next_tick, retry},
-    settings::Gui,
+    settings::{gui::Title, Gui},
+    string::ArrayCString,
     time::Duration,
     timer,
     timer::TimerState,
@@ -21,7 +23,7 @@ use asr::{
     Process,
 };
 
-use crate::unreal::{Module, UnrealPointer};
+use crate::unreal::{FName, FUObjectItem, FUrl, Module, TArray, UObject, UnrealPointer};
 
 asr::panic_handler!();
 asr::async_main!(nightly);
@@ -39,7 +41,7 @@ async fn main() {
                 let mut watchers = Watchers::default();
 
                 // Perform memory scanning to look for the addresses we need
-                let addresses = Addresses::init(&process).await;
+                let mut addresses = Addresses::init(&process).await;
 
                 loop {
                     // Splitting logic. Adapted from OG LiveSplit:
@@ -49,7 +51,7 @@ async fn main() {
                     // 3. If reset does not return true, then the split action will be run.
                     // 4. If the timer is currently not running (and not paused), then the start action will be run.ù
                     settings.update();
-                    update_loop(&process, &addresses, &mut watchers);
+                    update_loop(&process, &mut addresses, &mut watchers);
 
                     let timer_state = timer::state();
                     if timer_state == TimerState::Running || timer_state == TimerState::Paused {
@@ -85,6 +87,16 @@ async fn main() {
                         }
                     }
 
+                    // Looking for the game subsystems can take a while, so it's only done
+                    // once the timer has been updated for the current tick
+                    addresses.update_subsystems(
+                        &process,
+                        watchers
+                            .is_loading
+                            .pair
+                            .is_some_and(|is_loading| is_loading.old && !is_loading.current),
+                    );
+
                     next_tick().await;
                 }
             })
@@ -95,8 +107,37 @@ async fn main() {
 #[derive(Default)]
 struct Watchers {
     is_loading: Watcher<bool>,
+    is_paused: Watcher<bool>,
+    in_cinematic: Watcher<bool>,
+    session: Watcher<Session>,
+    players: Watcher<u32>,
     player_exp: Watcher<u64>,
+    player_xp: Watcher<u32>,
+    hero: Watcher<Option<Hero>>,
+    difficulty: Watcher<Option<Difficulty>>,
+    persistent_level: Watcher<Option<UObject>>,
+    map_name: Watcher<Option<ArrayString<MAP_NAME_LEN>>>,
     level: Watcher<Map>,
+    district: Watcher<Map>,
+    from_main_menu: Watcher<bool>,
+    loaded_levels: Watcher<TArray>,
+    spawn_position: Option<[f32; 3]>,
+    active_quests: Watcher<usize>,
+    quests_settled: bool,
+    current_mission: Watcher<Option<Mission>>,
+    awaiting_input: Watcher<bool>,
+    completed_quests: Watcher<usize>,
+    last_completed_mission: Watcher<Option<Mission>>,
+    defeated_bosses: Watcher<usize>,
+    last_defeated_boss: Watcher<Option<Boss>>,
+    play_time: Watcher<f64>,
+    start_play_time: f64,
+    nest_destroyed: Watcher<bool>,
+    nests_cleared: Watcher<u32>,
+    player_health: Watcher<Option<f32>>,
+    deaths: Watcher<u32>,
+    unlocked_safehouses: Watcher<usize>,
+    last_unlocked_safehouse: Watcher<Option<Safehouse>>,
 }
 
 #[derive(Gui)]
@@ -104,14 +145,372 @@ struct Settings {
     #[default = true]
     /// AUTO START
     start: bool,
+    /// START MODE
+    start_mode: StartMode,
+    #[default = false]
+    /// Auto start when joining a co-op session as a client
+    client_start: bool,
+    #[default = false]
+    /// AUTO RESET
+    reset: bool,
+    #[default = false]
+    /// Reset on death
+    reset_on_death: bool,
+    /// TIMING METHOD
+    timing_method: TimingMethod,
+    #[default = false]
+    /// Remove paused time (single player only)
+    remove_paused: bool,
+    #[default = false]
+    /// Remove cinematics
+    remove_cinematics: bool,
+    /// AUTO START ONLY ON DIFFICULTY
+    start_difficulty: DifficultyFilter,
+    /// Auto start heroes
+    #[heading_level = 0]
+    _start_heroes: Title,
+    #[default = true]
+    /// Layla
+    start_layla: bool,
+    #[default = true]
+    /// Devinder
+    start_devinder: bool,
+    #[default = true]
+    /// Jacob
+    start_jacob: bool,
+    #[default = true]
+    /// Remi
+    start_remi: bool,
+    /// End of run
+    #[heading_level = 0]
+    _end_of_run: Title,
+    #[default = true]
+    /// Final split, independent from the other split settings
+    end_of_run: bool,
+    /// FINAL SPLIT TRIGGER
+    end_of_run_trigger: EndOfRunTrigger,
+    /// Splits
+    #[heading_level = 0]
+    _splits: Title,
+    #[default = true]
+    /// Split when moving from Redfall Commons to Burial Point
+    district_transition: bool,
+    #[default = true]
+    /// Split when playing as a client in a co-op session
+    client_splits: bool,
+    /// Vampire nest splits
+    ///
+    /// Splits on every nest cleared within the selected range.
+    /// Pick the same nest twice in order to split only when clearing it.
+    #[heading_level = 1]
+    _nests: Title,
+    #[default = false]
+    /// Split on nest clears
+    nest_split: bool,
+    /// From nest
+    nest_split_from: SplitNest,
+    /// To nest
+    nest_split_to: SplitNest,
+    /// Mission splits
+    ///
+    /// The Hollow Man, Miss Whisper and The Black Sun are completed right after
+    /// defeating their vampire god, so they're off by default in favour of the boss splits.
+    #[heading_level = 1]
+    _missions: Title,
+    #[default = true]
+    /// Welcome to Redfall
+    mission_welcome_to_redfall: bool,
+    #[default = true]
+    /// Light in the Dark
+    mission_light_in_the_dark: bool,
+    #[default = false]
+    /// The Hollow Man
+    mission_hollow_man: bool,
+    #[default = false]
+    /// Miss Whisper
+    mission_miss_whisper: bool,
+    #[default = true]
+    /// Crossing Over
+    mission_crossing_over: bool,
+    #[default = true]
+    /// Bright Lights
+    mission_bright_lights: bool,
+    #[default = false]
+    /// The Black Sun
+    mission_black_sun: bool,
+    /// Boss splits
+    #[heading_level = 1]
+    _bosses: Title,
+    #[default = true]
+    /// The Hollow Man
+    boss_hollow_man: bool,
+    #[default = true]
+    /// Miss Whisper
+    boss_miss_whisper: bool,
+    #[default = true]
+    /// The Black Sun
+    boss_black_sun: bool,
+    /// Safehouse splits
+    #[heading_level = 1]
+    _safehouses: Title,
+    #[default = true]
+    /// Fire Station
+    safehouse_fire_station: bool,
+    #[default = true]
+    /// Marina
+    safehouse_marina: bool,
+    #[default = true]
+    /// Town Hall
+    safehouse_town_hall: bool,
+    #[default = true]
+    /// Brewery
+    safehouse_brewery: bool,
+    #[default = true]
+    /// Church
+    safehouse_church: bool,
+    #[default = true]
+    /// Farmhouse
+    safehouse_farmhouse: bool,
+    #[default = true]
+    /// Lumber Mill
+    safehouse_lumber_mill: bool,
+    #[default = true]
+    /// Cannery
+    safehouse_cannery: bool,
+    /// Level splits
+    ///
+    /// Splits on every level up within the selected range.
+    /// Pick the same level twice in order to split only when reaching it.
+    #[heading_level = 1]
+    _levels: Title,
+    #[default = false]
+    /// Split on level ups
+    level_split: bool,
+    /// From level
+    level_split_from: SplitLevel,
+    /// To level
+    level_split_to: SplitLevel,
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum StartMode {
+    /// New game (Redfall Commons)
+    #[default]
+    NewGame,
+    /// Burial Point IL (load from the main menu)
+    BurialPoint,
+    /// Practice (first movement or mission accepted after a load)
+    Practice,
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum TimingMethod {
+    /// Load Removed Time
+    #[default]
+    LoadRemoved,
+    /// In-Game Time (save playtime)
+    InGameTime,
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum EndOfRunTrigger {
+    /// The Black Sun is defeated
+    #[default]
+    BlackSunDefeated,
+    /// The ending cinematic starts after defeating the Black Sun
+    EndingCinematic,
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum SplitNest {
+    /// Any
+    #[default]
+    Any,
+    /// 1st nest
+    First,
+    /// 2nd nest
+    Second,
+    /// 3rd nest
+    Third,
+    /// 4th nest
+    Fourth,
+    /// 5th nest
+    Fifth,
+    /// 6th nest
+    Sixth,
+    /// 7th nest
+    Seventh,
+    /// 8th nest
+    Eighth,
+    /// 9th nest
+    Ninth,
+    /// 10th nest
+    Tenth,
+}
+
+impl SplitNest {
+    const fn count(self) -> Option<u32> {
+        match self {
+            Self::Any => None,
+            Self::First => Some(1),
+            Self::Second => Some(2),
+            Self::Third => Some(3),
+            Self::Fourth => Some(4),
+            Self::Fifth => Some(5),
+            Self::Sixth => Some(6),
+            Self::Seventh => Some(7),
+            Self::Eighth => Some(8),
+            Self::Ninth => Some(9),
+            Self::Tenth => Some(10),
+        }
+    }
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum SplitLevel {
+    /// Any
+    #[default]
+    Any,
+    /// Level 5
+    Level5,
+    /// Level 10
+    Level10,
+    /// Level 15
+    Level15,
+    /// Level 20
+    Level20,
+    /// Level 25
+    Level25,
+    /// Level 30
+    Level30,
+    /// Level 35
+    Level35,
+    /// Level 40
+    Level40,
+    /// Level 45
+    Level45,
+    /// Level 50
+    Level50,
+}
+
+impl SplitLevel {
+    const fn level(self) -> Option<u64> {
+        match self {
+            Self::Any => None,
+            Self::Level5 => Some(5),
+            Self::Level10 => Some(10),
+            Self::Level15 => Some(15),
+            Self::Level20 => Some(20),
+            Self::Level25 => Some(25),
+            Self::Level30 => Some(30),
+            Self::Level35 => Some(35),
+            Self::Level40 => Some(40),
+            Self::Level45 => Some(45),
+            Self::Level50 => Some(50),
+        }
+    }
+}
+
+#[derive(Gui, Copy, Clone, PartialEq)]
+enum DifficultyFilter {
+    /// Any difficulty
+    #[default]
+    Any,
+    /// Easy
+    Easy,
+    /// Normal
+    Normal,
+    /// Hard
+    Hard,
+    /// Insane
+    Insane,
+}
+
+impl DifficultyFilter {
+    fn matches(self, difficulty: Option<Difficulty>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Easy => difficulty == Some(Difficulty::Easy),
+            Self::Normal => difficulty == Some(Difficulty::Normal),
+            Self::Hard => difficulty == Some(Difficulty::Hard),
+            Self::Insane => difficulty == Some(Difficulty::Insane),
+        }
+    }
+}
+
+impl Settings {
+    const fn mission(&self, mission: Mission) -> bool {
+        match mission {
+            Mission::WelcomeToRedfall => self.mission_welcome_to_redfall,
+            Mission::LightInTheDark => self.mission_light_in_the_dark,
+            Mission::HollowMan => self.mission_hollow_man,
+            Mission::MissWhisper => self.mission_miss_whisper,
+            Mission::CrossingOver => self.mission_crossing_over,
+            Mission::BrightLights => self.mission_bright_lights,
+            Mission::BlackSun => self.mission_black_sun,
+        }
+    }
+
+    /// If every hero is enabled there's nothing to filter, so auto start
+    /// doesn't depend on the hero being detected correctly
+    const fn start_hero(&self, hero: Option<Hero>) -> bool {
+        match hero {
+            Some(Hero::Layla) => self.start_layla,
+            Some(Hero::Devinder) => self.start_devinder,
+            Some(Hero::Jacob) => self.start_jacob,
+            Some(Hero::Remi) => self.start_remi,
+            None => self.start_layla && self.start_devinder && self.start_jacob && self.start_remi,
+        }
+    }
+
+    const fn boss(&self, boss: Boss) -> bool {
+        match boss {
+            Boss::HollowMan => self.boss_hollow_man,
+            Boss::MissWhisper => self.boss_miss_whisper,
+            Boss::BlackSun => self.boss_black_sun,
+        }
+    }
+
+    const fn safehouse(&self, safehouse: Safehouse) -> bool {
+        match safehouse {
+            Safehouse::FireStation => self.safehouse_fire_station,
+            Safehouse::Marina => self.safehouse_marina,
+            Safehouse::TownHall => self.safehouse_town_hall,
+            Safehouse::Brewery => self.safehouse_brewery,
+            Safehouse::Church => self.safehouse_church,
+            Safehouse::Farmhouse => self.safehouse_farmhouse,
+            Safehouse::LumberMill => self.safehouse_lumber_mill,
+            Safehouse::Cannery => self.safehouse_cannery,
+        }
+    }
 }
 
 struct Addresses {
     unreal_module: Module,
-    current_level: UnrealPointer<4>,
+    persistent_level: UnrealPointer<3>,
+    loaded_levels: UnrealPointer<3>,
+    streamed_levels: ArrayVec<(Map, UnrealPointer<1>), MAX_STREAMED_LEVELS>,
+    world_url: UnrealPointer<3>,
     player_exp: UnrealPointer<8>,
+    player_xp: UnrealPointer<8>,
+    player_pawn: UnrealPointer<6>,
+    player_health: UnrealPointer<8>,
+    player_position: UnrealPointer<8>,
+    difficulty: Option<UnrealPointer<1>>,
     no_of_online_players: UnrealPointer<4>,
+    server_connection: UnrealPointer<4>,
     is_loading_single: UnrealPointer<3>,
+    is_loading_multi: UnrealPointer<6>,
+    pauser: UnrealPointer<5>,
+    cinematic_mode: UnrealPointer<6>,
+    completed_quests: Option<UnrealPointer<1>>,
+    active_quests: Option<UnrealPointer<1>>,
+    defeated_bosses: Option<UnrealPointer<1>>,
+    subsystems: [Option<FUObjectItem>; SUBSYSTEMS.len()],
+    next_object: u64,
+    play_time: Option<UnrealPointer<2>>,
+    nest_destroyed: UnrealPointer<5>,
+    unlocked_safehouses: Option<UnrealPointer<1>>,
 }
 
 impl Addresses {
@@ -125,8 +524,9 @@ impl Addresses {
 
         let unreal = retry(|| Module::attach(game, main_module)).await;
 
-        let current_level =
-            UnrealPointer::<4>::new(unreal.g_engine(), &["GameViewport", "World", "0x4B8", "0"]);
+        let persistent_level = world_pointer(&unreal, &["PersistentLevel"]);
+        let loaded_levels = world_pointer(&unreal, &["Levels"]);
+        let world_url = world_pointer(&unreal, &["URL"]);
         let player_exp = UnrealPointer::<8>::new(
             unreal.g_engine(),
             &[
@@ -140,6 +540,57 @@ impl Addresses {
                 "CurrentExperienceAndLevel.Level",
             ],
         );
+        let player_xp = UnrealPointer::<8>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "Pawn",
+                "Experience",
+                "CurrentExperienceAndLevel.Experience",
+            ],
+        );
+        let player_pawn = UnrealPointer::<6>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "Pawn",
+            ],
+        );
+        let player_health = UnrealPointer::<8>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "Pawn",
+                "HealthComponent",
+                "CurrentHealth",
+            ],
+        );
+        let player_position = UnrealPointer::<8>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "Pawn",
+                "RootComponent",
+                "RelativeLocation",
+            ],
+        );
+        // Unlike the game subsystems, the matchmaking client is a property of the GameInstance
         let no_of_online_players = UnrealPointer::<4>::new(
             unreal.g_engine(),
             &[
@@ -149,44 +600,239 @@ impl Addresses {
                 "0x60",
             ],
         );
+        let server_connection = world_pointer(&unreal, &["NetDriver", "ServerConnection"]);
         let is_loading_single = UnrealPointer::<3>::new(
             unreal.g_engine(),
             &["GameViewport", "GameInstance", "0x570"],
         );
+        let is_loading_multi = UnrealPointer::<6>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "bShowingLoadingScreen",
+            ],
+        );
+
+        let pauser = world_pointer(
+            &unreal,
+            &["PersistentLevel", "WorldSettings", "PauserPlayerState"],
+        );
+
+        let cinematic_mode = UnrealPointer::<6>::new(
+            unreal.g_engine(),
+            &[
+                "GameViewport",
+                "GameInstance",
+                "LocalPlayers",
+                "0",
+                "PlayerController",
+                "bCinematicMode",
+            ],
+        );
+
+        let nest_destroyed =
+            world_pointer(&unreal, &["GameState", "ActiveVampireNest", "bIsDestroyed"]);
 
         Self {
             unreal_module: unreal,
-            current_level,
+            persistent_level,
+            loaded_levels,
+            streamed_levels: ArrayVec::new(),
+            world_url,
             player_exp,
+            player_xp,
+            player_pawn,
+            player_health,
+            player_position,
+            difficulty: None,
             no_of_online_players,
+            server_connection,
             is_loading_single,
+            is_loading_multi,
+            pauser,
+            cinematic_mode,
+            completed_quests: None,
+            active_quests: None,
+            defeated_bosses: None,
+            subsystems: [None; SUBSYSTEMS.len()],
+            next_object: 0,
+            play_time: None,
+            nest_destroyed,
+            unlocked_safehouses: None,
+        }
+    }
+
+    /// Keeps track of the game subsystems, which are looked up in GUObjectArray.
+    ///
+    /// Subsystems get garbage collected when going back to the main menu or loading another save,
+    /// after which their slot in GUObjectArray can be reused by any other object, so they're
+    /// checked again whenever a load ends. Missing subsystems are then looked up a slice
+    /// of GUObjectArray at a time, as scanning it all at once would stall the autosplitter.
+    fn update_subsystems(&mut self, game: &Process, load_ended: bool) {
+        let mut changed = false;
+
+        if load_ended {
+            for (subsystem, class_name) in self.subsystems.iter_mut().zip(SUBSYSTEMS) {
+                if subsystem.is_some_and(|subsystem| {
+                    !subsystem.holds_instance_of(game, &self.unreal_module, class_name)
+                }) {
+                    *subsystem = None;
+                    changed = true;
+                }
+            }
+        } else if self.subsystems.iter().any(Option::is_none) {
+            if self.next_object >= self.unreal_module.num_objects(game) {
+                self.next_object = 0;
+            }
+            let range = self.next_object..self.next_object + OBJECTS_PER_UPDATE;
+            self.next_object = range.end;
+
+            let found = self
+                .unreal_module
+                .find_objects_by_class(game, SUBSYSTEMS, range);
+            for (subsystem, item) in self.subsystems.iter_mut().zip(found) {
+                if subsystem.is_none() && item.is_some() {
+                    *subsystem = item;
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            self.update_subsystem_pointers();
         }
     }
+
+    fn update_subsystem_pointers(&mut self) {
+        let [quests, vampire_gods, difficulty, save_game, safehouses] = self
+            .subsystems
+            .map(|subsystem| subsystem.map(|subsystem| subsystem.address()));
+
+        self.completed_quests = quests.map(|base| UnrealPointer::new(base, &["CompletedQuests"]));
+        self.active_quests = quests.map(|base| UnrealPointer::new(base, &["ActiveQuests"]));
+        self.defeated_bosses =
+            vampire_gods.map(|base| UnrealPointer::new(base, &["DefeatedVampireGods"]));
+        self.difficulty = difficulty.map(|base| UnrealPointer::new(base, &["CurrentDifficulty"]));
+        self.play_time =
+            save_game.map(|base| UnrealPointer::new(base, &["CurrentSaveGame", "PlayTime"]));
+        self.unlocked_safehouses =
+            safehouses.map(|base| UnrealPointer::new(base, &["UnlockedSafehouses"]));
+    }
 }
 
-fn update_loop(game: &Process, addresses: &Addresses, watchers: &mut Watchers) {
+/// Creates a pointer path starting from the currently loaded `UWorld`.
+/// If GWorld couldn't be found, the world is reached through the game viewport instead.
+fn world_pointer<const CAP: usize>(unreal: &Module, fields: &[&'static str]) -> UnrealPointer<CAP> {
+    match unreal.g_world() {
+        Some(g_world) => UnrealPointer::new(g_world, fields),
+        _ => {
+            let fields: ArrayVec<&'static str, CAP> = ["GameViewport", "World"]
+                .into_iter()
+                .chain(fields.iter().copied())
+                .take(CAP)
+                .collect();
+            UnrealPointer::new(unreal.g_engine(), &fields)
+        }
+    }
+}
+
+fn update_loop(game: &Process, addresses: &mut Addresses, watchers: &mut Watchers) {
+    let first_update = watchers.is_loading.pair.is_none();
+
     let no_of_online_players = addresses
         .no_of_online_players
         .deref::<u32>(&game, &addresses.unreal_module)
         .unwrap_or_default();
 
-    let level = addresses
-        .current_level
-        .deref::<[u16; 100]>(&game, &addresses.unreal_module)
-        .map(|n| n.map(|val| val as u8));
-
-    let level = level.map(|val| {
-        let map_name = &val[..val.iter().position(|&b| b == 0).unwrap_or(val.len())];
-
-        match map_name {
-            b"/Game/Maps/Campaign/FrontEnd/FrontEnd" => Map::MainMenu,
-            b"/Game/Maps/Campaign/District_01/District_01" => Map::RedfallCommons,
-            b"/Game/Maps/Campaign/District_02/District_02" => Map::BurialPoint,
-            _ => match watchers.level.pair {
-                Some(x) => x.current,
-                _ => Map::MainMenu,
+    // Clients are the only ones holding a connection to a server
+    watchers
+        .session
+        .update_infallible(match no_of_online_players {
+            0 => Session::Solo,
+            _ => match addresses
+                .server_connection
+                .deref::<u64>(game, &addresses.unreal_module)
+            {
+                Some(0) | None => Session::Host,
+                _ => Session::Client,
             },
+        });
+
+    watchers
+        .players
+        .update_infallible(no_of_online_players.max(1));
+
+    // The current map is identified by the name of the package the persistent level belongs to.
+    // While a new map is being loaded the persistent level might not be set yet,
+    // so in that case we fall back to the map path stored in the world's URL.
+    let persistent_level = addresses
+        .persistent_level
+        .deref_uobject(game, &addresses.unreal_module);
+    let map_name = match persistent_level {
+        Some(persistent_level) => {
+            persistent_level.get_package_name::<MAP_NAME_LEN>(game, &addresses.unreal_module)
         }
+        _ => addresses
+            .world_url
+            .deref::<FUrl>(game, &addresses.unreal_module)
+            .and_then(|url| url.map().read::<MAP_NAME_LEN>(game)),
+    };
+    watchers
+        .persistent_level
+        .update_infallible(persistent_level);
+    watchers.map_name.update_infallible(map_name);
+
+    // Dungeons, boss arenas and vampire nests are streamed in on top of the district they
+    // belong to, so they can only be found in the list of loaded levels, after the persistent one.
+    // Resolving the package of every level is expensive, so the list is only checked
+    // again when it changes.
+    let loaded_levels = watchers.loaded_levels.update_infallible(
+        addresses
+            .loaded_levels
+            .deref::<TArray>(game, &addresses.unreal_module)
+            .unwrap_or_default(),
+    );
+    if first_update || loaded_levels.changed() {
+        addresses.streamed_levels = (1..loaded_levels.current.len())
+            .filter_map(|index| {
+                let map = loaded_levels
+                    .current
+                    .get_uobject(game, index)?
+                    .get_package_name::<MAP_NAME_LEN>(game, &addresses.unreal_module)
+                    .and_then(|name| Map::from_path(name.as_bytes()))?;
+                let is_visible = UnrealPointer::new(
+                    loaded_levels.current.get_address::<u64>(index)?,
+                    &["bIsVisible"],
+                );
+                Some((map, is_visible))
+            })
+            .take(MAX_STREAMED_LEVELS)
+            .collect();
+    }
+
+    // Sub-levels can be streamed in ahead of time and stay hidden until the player
+    // gets there, so only the visible ones take precedence over the persistent level
+    let streamed_level = addresses
+        .streamed_levels
+        .iter()
+        .find(|(_, is_visible)| {
+            is_visible
+                .deref_bool(game, &addresses.unreal_module)
+                .unwrap_or_default()
+        })
+        .map(|&(map, _)| map);
+
+    let level = map_name.map(|name| streamed_level.or_else(|| Map::from_path(name.as_bytes())));
+
+    let level = level.map(|map| {
+        map.unwrap_or_else(|| match watchers.level.pair {
+            Some(x) => x.current,
+            _ => Map::MainMenu,
+        })
     });
 
     watchers
@@ -199,24 +845,399 @@ fn update_loop(game: &Process, addresses: &Addresses, watchers: &mut Watchers) {
                     .unwrap_or_default()
                     != 0
             }
-            _ => level.is_some_and(|val| val == Map::MainMenu),
+            // In online sessions the loading flag on the GameInstance is not reliable,
+            // so we rely on the loading screen of the local player instead. If that can't
+            // be read, we fall back to considering the main menu as a loading screen.
+            _ => addresses
+                .is_loading_multi
+                .deref_bool(game, &addresses.unreal_module)
+                .unwrap_or_else(|| level.is_some_and(|val| val == Map::MainMenu)),
         });
 
+    // The world only gets paused in single player, with the pausing player stored in the world settings
     watchers
+        .is_paused
+        .update_infallible(match no_of_online_players {
+            0 => addresses
+                .pauser
+                .deref::<u64>(game, &addresses.unreal_module)
+                .is_some_and(|val| val != 0),
+            _ => false,
+        });
+
+    watchers.in_cinematic.update_infallible(
+        addresses
+            .cinematic_mode
+            .deref_bool(game, &addresses.unreal_module)
+            .unwrap_or_default(),
+    );
+
+    let level = watchers
         .level
         .update_infallible(level.unwrap_or_else(|| Map::MainMenu));
+
+    // Interiors, and the main menu while the world is being replaced, can show up between
+    // two districts, so the district is only updated when the player is actually in one
+    let district = match level.current {
+        Map::RedfallCommons | Map::BurialPoint => level.current,
+        _ => watchers
+            .district
+            .pair
+            .map_or(level.current, |district| district.current),
+    };
+    watchers.district.update_infallible(district);
+
+    // Set when the game goes back to the main menu, and cleared as soon as the first load
+    // out of it ends. The prologue is played before reaching Redfall Commons with a new
+    // campaign, so it doesn't clear it. The main menu is also used as a fallback while
+    // the world is unavailable during loads, so only the actual FrontEnd map sets it.
+    let on_main_menu =
+        map_name.is_some_and(|name| Map::from_path(name.as_bytes()) == Some(Map::MainMenu));
+    let load_ended = watchers
+        .is_loading
+        .pair
+        .is_some_and(|is_loading| is_loading.old && !is_loading.current);
+    let was_from_main_menu = watchers.from_main_menu.pair.is_some_and(|val| val.current);
+    let from_main_menu = match level.current {
+        _ if on_main_menu => true,
+        Map::MainMenu | Map::Prologue => was_from_main_menu,
+        _ => was_from_main_menu && !load_ended,
+    };
+    watchers.from_main_menu.update_infallible(from_main_menu);
+
+    // The full path of the persistent level is only published for debugging purposes,
+    // so it's only rebuilt when the persistent level or the name of the map change.
+    // Unknown maps leave the current `Map` unchanged, so that can't be relied upon here.
+    if first_update
+        || watchers
+            .persistent_level
+            .pair
+            .is_some_and(|val| val.changed())
+        || watchers.map_name.pair.is_some_and(|val| val.changed())
+    {
+        timer::set_variable(
+            "Map path",
+            &persistent_level
+                .and_then(|persistent_level| {
+                    persistent_level.get_full_path::<MAP_NAME_LEN>(game, &addresses.unreal_module)
+                })
+                .unwrap_or_default(),
+        );
+    }
+
+    // A nest is considered cleared once its heart gets destroyed while the player is inside
+    let nest_destroyed =
+        watchers
+            .nest_destroyed
+            .update_infallible(watchers.level.pair.is_some_and(|level| {
+                level.current == Map::VampireNest
+                    && addresses
+                        .nest_destroyed
+                        .deref_bool(game, &addresses.unreal_module)
+                        .unwrap_or_default()
+            }));
+
+    // Cleared nests are only counted within a run, so the count restarts whenever the timer is reset
+    let nests_cleared = match timer::state() {
+        TimerState::NotRunning => 0,
+        _ => {
+            watchers.nests_cleared.pair.map_or(0, |val| val.current)
+                + u32::from(nest_destroyed.current && !nest_destroyed.old)
+        }
+    };
+    watchers.nests_cleared.update_infallible(nests_cleared);
     watchers.player_exp.update_infallible(
         addresses
             .player_exp
             .deref::<u64>(&game, &addresses.unreal_module)
             .unwrap_or_default(),
     );
+    watchers.player_xp.update_infallible(
+        addresses
+            .player_xp
+            .deref::<u32>(game, &addresses.unreal_module)
+            .unwrap_or_default(),
+    );
+
+    // Each hero is a separate pawn class, so the class name is enough to tell them apart
+    watchers.hero.update_infallible(
+        addresses
+            .player_pawn
+            .deref_uobject(game, &addresses.unreal_module)
+            .and_then(|pawn| pawn.get_class_name::<64>(game, &addresses.unreal_module))
+            .and_then(|name| Hero::from_class_name(name.as_bytes())),
+    );
+
+    watchers.player_health.update_infallible(
+        addresses
+            .player_health
+            .deref::<f32>(game, &addresses.unreal_module),
+    );
+
+    // Same as cleared nests, deaths are only counted within a run
+    let deaths = match timer::state() {
+        TimerState::NotRunning => 0,
+        _ => watchers.deaths.pair.map_or(0, |val| val.current) + u32::from(player_died(watchers)),
+    };
+    watchers.deaths.update_infallible(deaths);
+
+    watchers.difficulty.update_infallible(
+        addresses
+            .difficulty
+            .as_ref()
+            .and_then(|difficulty| difficulty.deref::<u8>(game, &addresses.unreal_module))
+            .and_then(Difficulty::from_id),
+    );
+
+    // Completed quests are stored as a list of quest IDs, in the order they have been completed.
+    // We only need to look at the latest entry in order to know which mission has just been completed.
+    let (completed_quests, last_completed_quest) = read_name_list(
+        game,
+        &addresses.unreal_module,
+        addresses.completed_quests.as_ref(),
+    );
+    watchers
+        .completed_quests
+        .update_infallible(completed_quests);
+    watchers.last_completed_mission.update_infallible(
+        last_completed_quest.and_then(|name| Mission::from_quest_id(name.as_bytes())),
+    );
+
+    // Same goes for the vampire gods, which are recorded in the order they have been defeated
+    let (defeated_bosses, last_defeated_boss) = read_name_list(
+        game,
+        &addresses.unreal_module,
+        addresses.defeated_bosses.as_ref(),
+    );
+    watchers.defeated_bosses.update_infallible(defeated_bosses);
+    watchers
+        .last_defeated_boss
+        .update_infallible(last_defeated_boss.and_then(|name| Boss::from_id(name.as_bytes())));
+
+    let (unlocked_safehouses, last_unlocked_safehouse) = read_name_list(
+        game,
+        &addresses.unreal_module,
+        addresses.unlocked_safehouses.as_ref(),
+    );
+    watchers
+        .unlocked_safehouses
+        .update_infallible(unlocked_safehouses);
+    watchers.last_unlocked_safehouse.update_infallible(
+        last_unlocked_safehouse.and_then(|name| Safehouse::from_id(name.as_bytes())),
+    );
+
+    // Practice start: the timer is armed during loads and starts on the first
+    // player movement or accepted mission afterwards.
+    // Movement is measured on the horizontal plane from where the player was when the load ended,
+    // so small position adjustments and the pawn settling on the ground are ignored.
+    let player_position = addresses
+        .player_position
+        .deref::<[f32; 3]>(game, &addresses.unreal_module);
+    if watchers.is_loading.pair.is_none_or(|val| val.current) {
+        watchers.spawn_position = None;
+    } else if watchers.spawn_position.is_none() {
+        watchers.spawn_position = player_position;
+    }
+    let moved = match (watchers.spawn_position, player_position) {
+        (Some([spawn_x, spawn_y, _]), Some([x, y, _])) => {
+            (x - spawn_x) * (x - spawn_x) + (y - spawn_y) * (y - spawn_y)
+                > MOVEMENT_THRESHOLD * MOVEMENT_THRESHOLD
+        }
+        _ => false,
+    };
+    let (active_quests, _) = read_name_list(
+        game,
+        &addresses.unreal_module,
+        addresses.active_quests.as_ref(),
+    );
+    let active_quests = watchers.active_quests.update_infallible(active_quests);
+    // The quest list gets filled in over the first updates after a load, so quests only
+    // count as accepted once the list has stayed the same for an update, or the player has moved
+    let mission_accepted = watchers.quests_settled && active_quests.current > active_quests.old;
+    watchers.quests_settled = match &watchers.is_loading.pair {
+        Some(is_loading) if !is_loading.current && !is_loading.old => {
+            watchers.quests_settled || moved || active_quests.current == active_quests.old
+        }
+        _ => false,
+    };
+    let awaiting_input = match &watchers.is_loading.pair {
+        Some(is_loading) if is_loading.current => true,
+        _ => {
+            watchers.awaiting_input.pair.is_some_and(|val| val.current)
+                && !moved
+                && !mission_accepted
+        }
+    };
+    watchers.awaiting_input.update_infallible(awaiting_input);
+
+    // The list of active quests only needs to be searched again when quests get accepted or completed
+    let quests_changed = first_update
+        || watchers.active_quests.pair.is_some_and(|val| val.changed())
+        || watchers
+            .completed_quests
+            .pair
+            .is_some_and(|val| val.changed());
+    let current_mission = if quests_changed {
+        find_current_mission(
+            game,
+            &addresses.unreal_module,
+            addresses.active_quests.as_ref(),
+        )
+    } else {
+        watchers.current_mission.pair.and_then(|val| val.current)
+    };
+    watchers.current_mission.update_infallible(current_mission);
+
+    // The save game is unavailable during loads and on the main menu, so in that case
+    // we keep the last valid playtime instead of setting the game time back to zero.
+    // Garbage values are discarded too, as they can't be converted to a duration.
+    if let Some(play_time) = addresses
+        .play_time
+        .as_ref()
+        .and_then(|play_time| play_time.deref::<f64>(game, &addresses.unreal_module))
+        .filter(|val| (0.0..=MAX_PLAY_TIME).contains(val))
+    {
+        watchers.play_time.update_infallible(play_time);
+    }
+
+    // Runs can be started from an existing save, so the game time is measured
+    // from the playtime the save had when the timer started
+    if timer::state() == TimerState::NotRunning {
+        watchers.start_play_time = watchers.play_time.pair.map_or(0.0, |val| val.current);
+    }
+
+    update_variables(watchers, first_update);
+}
+
+/// Publishes the tracked game state as timer variables, so it can be shown in the layout.
+/// Every variable is published on the first update, and then only when its value changes.
+fn update_variables(watchers: &Watchers, first_update: bool) {
+    if let Some(level) = changed_value(&watchers.level, first_update) {
+        timer::set_variable("Map", level.name());
+    }
+    if let Some(is_loading) = changed_value(&watchers.is_loading, first_update) {
+        timer::set_variable("Loading", if is_loading { "Yes" } else { "No" });
+    }
+    if let Some(player_exp) = changed_value(&watchers.player_exp, first_update) {
+        timer::set_variable_int("Level", player_level(player_exp));
+    }
+    if let Some(player_xp) = changed_value(&watchers.player_xp, first_update) {
+        timer::set_variable_int("XP", player_xp);
+    }
+    if let Some(hero) = changed_value(&watchers.hero, first_update) {
+        timer::set_variable(
+            "Hero",
+            match hero {
+                Some(hero) => hero.name(),
+                _ => "Unknown",
+            },
+        );
+    }
+    if let Some(difficulty) = changed_value(&watchers.difficulty, first_update) {
+        timer::set_variable(
+            "Difficulty",
+            match difficulty {
+                Some(difficulty) => difficulty.name(),
+                _ => "Unknown",
+            },
+        );
+    }
+    if let Some(session) = changed_value(&watchers.session, first_update) {
+        timer::set_variable("Session", session.name());
+    }
+    if let Some(players) = changed_value(&watchers.players, first_update) {
+        timer::set_variable_int("Players", players);
+    }
+    // Side quests are not tracked, so only the current story mission is shown
+    if let Some(current_mission) = changed_value(&watchers.current_mission, first_update) {
+        timer::set_variable(
+            "Mission",
+            match current_mission {
+                Some(mission) => mission.name(),
+                _ => "-",
+            },
+        );
+    }
+    if let Some(nests_cleared) = changed_value(&watchers.nests_cleared, first_update) {
+        timer::set_variable_int("Nests cleared", nests_cleared);
+    }
+    if let Some(deaths) = changed_value(&watchers.deaths, first_update) {
+        timer::set_variable_int("Deaths", deaths);
+    }
+}
+
+/// Returns the current value of the watcher, if it needs to be published
+fn changed_value<T: Copy + PartialEq>(watcher: &Watcher<T>, first_update: bool) -> Option<T> {
+    watcher
+        .pair
+        .filter(|val| first_update || val.changed())
+        .map(|val| val.current)
+}
+
+/// Returns the most recently accepted story mission among the active quests.
+/// Side quests can be accepted after a story mission, so the whole list needs to be searched.
+fn find_current_mission<const CAP: usize>(
+    game: &Process,
+    module: &Module,
+    pointer: Option<&UnrealPointer<CAP>>,
+) -> Option<Mission> {
+    let list = pointer?.deref::<TArray>(game, module)?;
+    (0..list.len()).rev().find_map(|index| {
+        let name = list
+            .get::<FName>(game, index)?
+            .resolve::<64>(game, module)?;
+        Mission::from_quest_id(name.as_bytes())
+    })
+}
+
+/// Reads a `TArray<FName>` through the specified pointer path, returning the number
+/// of entries in the array and the name stored in the last one.
+fn read_name_list<const CAP: usize>(
+    game: &Process,
+    module: &Module,
+    pointer: Option<&UnrealPointer<CAP>>,
+) -> (usize, Option<ArrayCString<64>>) {
+    let list = pointer
+        .and_then(|pointer| pointer.deref::<TArray>(game, module))
+        .unwrap_or_default();
+    let last = list
+        .last::<FName>(game)
+        .and_then(|name| name.resolve(game, module));
+    (list.len(), last)
 }
 
 fn start(watchers: &Watchers, settings: &Settings) -> bool {
     if !settings.start {
         return false;
     }
+
+    // Joining someone else's session with a new character would otherwise look
+    // exactly like starting a new campaign
+    if !settings.client_start && is_client(watchers) {
+        return false;
+    }
+
+    let started = match settings.start_mode {
+        StartMode::NewGame => new_game_started(watchers),
+        StartMode::BurialPoint => burial_point_loaded(watchers),
+        StartMode::Practice => watchers
+            .awaiting_input
+            .pair
+            .is_some_and(|val| val.old && !val.current),
+    };
+
+    started
+        && settings.start_hero(watchers.hero.pair.and_then(|hero| hero.current))
+        && settings.start_difficulty.matches(
+            watchers
+                .difficulty
+                .pair
+                .and_then(|difficulty| difficulty.current),
+        )
+}
+
+/// Returns `true` when a load into Redfall Commons ends with a brand new character.
+fn new_game_started(watchers: &Watchers) -> bool {
     let Some(is_loading) = &watchers.is_loading.pair else {
         return false;
     };
@@ -233,28 +1254,509 @@ fn start(watchers: &Watchers, settings: &Settings) -> bool {
         && player_exp.current == 0
 }
 
-fn split(_watchers: &Watchers, _settings: &Settings) -> bool {
-    false
+/// Returns `true` when a load from the main menu into Burial Point ends,
+/// regardless of the progress of the loaded character.
+fn burial_point_loaded(watchers: &Watchers) -> bool {
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(level) = &watchers.level.pair else {
+        return false;
+    };
+
+    !is_loading.current
+        && is_loading.old
+        && level.current == Map::BurialPoint
+        && watchers.from_main_menu.pair.is_some_and(|val| val.old)
+}
+
+/// Returns `true` on the update the local player's health drops to zero.
+/// Respawning gives the player a new pawn, so the same death can't be counted twice.
+fn player_died(watchers: &Watchers) -> bool {
+    let Some(player_health) = &watchers.player_health.pair else {
+        return false;
+    };
+
+    player_health.old.is_some_and(|health| health > 0.0)
+        && player_health.current.is_some_and(|health| health <= 0.0)
+}
+
+fn split(watchers: &Watchers, settings: &Settings) -> bool {
+    if split_end_of_run(watchers, settings) {
+        return true;
+    }
+
+    if !settings.client_splits && is_client(watchers) {
+        return false;
+    }
+
+    split_district(watchers, settings)
+        || split_mission(watchers, settings)
+        || split_boss(watchers, settings)
+        || split_nest(watchers, settings)
+        || split_safehouse(watchers, settings)
+        || split_level(watchers, settings)
+}
+
+fn is_client(watchers: &Watchers) -> bool {
+    watchers
+        .session
+        .pair
+        .is_some_and(|session| session.current == Session::Client)
+}
+
+/// The final split is independent from the other split settings:
+/// the run ends either when the final vampire god is defeated, or when the
+/// ending cinematic leading to the credits starts.
+fn split_end_of_run(watchers: &Watchers, settings: &Settings) -> bool {
+    if !settings.end_of_run {
+        return false;
+    }
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(last_defeated_boss) = &watchers.last_defeated_boss.pair else {
+        return false;
+    };
+
+    if is_loading.current || is_loading.old || last_defeated_boss.current != Some(Boss::BlackSun) {
+        return false;
+    }
+
+    match settings.end_of_run_trigger {
+        EndOfRunTrigger::BlackSunDefeated => watchers
+            .defeated_bosses
+            .pair
+            .is_some_and(|val| val.current == val.old + 1),
+        // The first cinematic after the Black Sun is defeated is the ending one
+        EndOfRunTrigger::EndingCinematic => watchers
+            .in_cinematic
+            .pair
+            .is_some_and(|val| val.current && !val.old),
+    }
+}
+
+fn split_district(watchers: &Watchers, settings: &Settings) -> bool {
+    if !settings.district_transition {
+        return false;
+    }
+    let Some(district) = &watchers.district.pair else {
+        return false;
+    };
+
+    district.old == Map::RedfallCommons && district.current == Map::BurialPoint
+}
+
+fn split_mission(watchers: &Watchers, settings: &Settings) -> bool {
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(completed_quests) = &watchers.completed_quests.pair else {
+        return false;
+    };
+    let Some(last_completed_mission) = &watchers.last_completed_mission.pair else {
+        return false;
+    };
+
+    // The whole quest list gets populated at once when a save is loaded,
+    // so we only consider quests that are completed one at a time outside of loads
+    !is_loading.current
+        && !is_loading.old
+        && completed_quests.current == completed_quests.old + 1
+        && last_completed_mission
+            .current
+            .is_some_and(|mission| settings.mission(mission))
+}
+
+fn split_boss(watchers: &Watchers, settings: &Settings) -> bool {
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(defeated_bosses) = &watchers.defeated_bosses.pair else {
+        return false;
+    };
+    let Some(last_defeated_boss) = &watchers.last_defeated_boss.pair else {
+        return false;
+    };
+
+    !is_loading.current
+        && !is_loading.old
+        && defeated_bosses.current == defeated_bosses.old + 1
+        && last_defeated_boss
+            .current
+            .is_some_and(|boss| settings.boss(boss))
+}
+
+fn split_nest(watchers: &Watchers, settings: &Settings) -> bool {
+    if !settings.nest_split {
+        return false;
+    }
+    let Some(nest_destroyed) = &watchers.nest_destroyed.pair else {
+        return false;
+    };
+    let Some(nests_cleared) = &watchers.nests_cleared.pair else {
+        return false;
+    };
+
+    nest_destroyed.current
+        && !nest_destroyed.old
+        && settings
+            .nest_split_from
+            .count()
+            .is_none_or(|from| nests_cleared.current >= from)
+        && settings
+            .nest_split_to
+            .count()
+            .is_none_or(|to| nests_cleared.current <= to)
+}
+
+fn split_safehouse(watchers: &Watchers, settings: &Settings) -> bool {
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(unlocked_safehouses) = &watchers.unlocked_safehouses.pair else {
+        return false;
+    };
+    let Some(last_unlocked_safehouse) = &watchers.last_unlocked_safehouse.pair else {
+        return false;
+    };
+
+    !is_loading.current
+        && !is_loading.old
+        && unlocked_safehouses.current == unlocked_safehouses.old + 1
+        && last_unlocked_safehouse
+            .current
+            .is_some_and(|safehouse| settings.safehouse(safehouse))
 }
 
-fn reset(_watchers: &Watchers, _settings: &Settings) -> bool {
-    false
+fn split_level(watchers: &Watchers, settings: &Settings) -> bool {
+    if !settings.level_split {
+        return false;
+    }
+    let Some(is_loading) = &watchers.is_loading.pair else {
+        return false;
+    };
+    let Some(player_exp) = &watchers.player_exp.pair else {
+        return false;
+    };
+
+    let old_level = player_level(player_exp.old);
+    let current_level = player_level(player_exp.current);
+
+    // Loading a save makes the level jump straight to its saved value,
+    // so only levels gained outside of loads count as level ups
+    if is_loading.current
+        || is_loading.old
+        || current_level <= old_level
+        || current_level > MAX_LEVEL
+    {
+        return false;
+    }
+
+    // More than one level can be gained at once, so every level in between is checked
+    (old_level + 1..=current_level).any(|level| {
+        settings
+            .level_split_from
+            .level()
+            .is_none_or(|from| level >= from)
+            && settings.level_split_to.level().is_none_or(|to| level <= to)
+    })
+}
+
+/// Despite its name, `player_exp` is read from `CurrentExperienceAndLevel.Level`,
+/// which starts from 0 on a new character, while the game shows it starting from 1
+const fn player_level(player_exp: u64) -> u64 {
+    player_exp.saturating_add(1)
 }
 
-fn is_loading(watchers: &Watchers, _settings: &Settings) -> Option<bool> {
-    Some(watchers.is_loading.pair?.current)
+fn reset(watchers: &Watchers, settings: &Settings) -> bool {
+    // A reload early in the run also brings a character with no experience back
+    // into Redfall Commons, so the reset only fires on the first load after leaving
+    // the main menu. The flag gets cleared by that same load, so it's checked before it.
+    (settings.reset
+        && watchers.from_main_menu.pair.is_some_and(|val| val.old)
+        && new_game_started(watchers))
+        || (settings.reset_on_death && player_died(watchers))
 }
 
-fn game_time(_watchers: &Watchers, _settings: &Settings) -> Option<Duration> {
-    None
+fn is_loading(watchers: &Watchers, settings: &Settings) -> Option<bool> {
+    match settings.timing_method {
+        // Game time is entirely driven by the save's playtime, so it's kept paused
+        // in order to prevent LiveSplit from running it on its own between updates
+        TimingMethod::InGameTime => Some(true),
+        TimingMethod::LoadRemoved => Some(
+            watchers.is_loading.pair?.current
+                || (settings.remove_paused
+                    && watchers.is_paused.pair.is_some_and(|val| val.current))
+                || (settings.remove_cinematics
+                    && watchers.in_cinematic.pair.is_some_and(|val| val.current)),
+        ),
+    }
+}
+
+fn game_time(watchers: &Watchers, settings: &Settings) -> Option<Duration> {
+    match settings.timing_method {
+        TimingMethod::InGameTime => Some(Duration::seconds_f64(
+            (watchers.play_time.pair?.current - watchers.start_play_time).max(0.0),
+        )),
+        TimingMethod::LoadRemoved => None,
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Default)]
+enum Session {
+    #[default]
+    Solo,
+    Host,
+    Client,
+}
+
+impl Session {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Solo => "Solo",
+            Self::Host => "Host",
+            Self::Client => "Client",
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Default)]
 enum Map {
     #[default]
     MainMenu,
+    Prologue,
     RedfallCommons,
     BurialPoint,
+    // Story dungeons
+    Lighthouse,
+    WhalingMuseum,
+    MoviePalace,
+    Hospital,
+    Mine,
+    Church,
+    AevumFacility,
+    // Boss arenas
+    HollowManArena,
+    MissWhisperArena,
+    BlackSunArena,
+    // Vampire nests are procedurally assigned from a pool of interiors,
+    // so there's no point in telling them apart
+    VampireNest,
+}
+
+impl Map {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::MainMenu => "Main Menu",
+            Self::Prologue => "Prologue",
+            Self::RedfallCommons => "Redfall Commons",
+            Self::BurialPoint => "Burial Point",
+            Self::Lighthouse => "Lighthouse",
+            Self::WhalingMuseum => "Whaling Museum",
+            Self::MoviePalace => "Movie Palace",
+            Self::Hospital => "Hospital",
+            Self::Mine => "Mine",
+            Self::Church => "Church",
+            Self::AevumFacility => "Aevum Facility",
+            Self::HollowManArena => "The Hollow Man's Lair",
+            Self::MissWhisperArena => "Miss Whisper's Lair",
+            Self::BlackSunArena => "The Black Sun's Lair",
+            Self::VampireNest => "Vampire Nest",
+        }
+    }
+
+    fn from_path(path: &[u8]) -> Option<Self> {
+        match path {
+            b"/Game/Maps/Campaign/FrontEnd/FrontEnd" => Some(Self::MainMenu),
+            b"/Game/Maps/Campaign/Prologue/Prologue" => Some(Self::Prologue),
+            b"/Game/Maps/Campaign/District_01/District_01" => Some(Self::RedfallCommons),
+            b"/Game/Maps/Campaign/District_02/District_02" => Some(Self::BurialPoint),
+            b"/Game/Maps/Campaign/District_01/Dungeons/Lighthouse/Lighthouse" => {
+                Some(Self::Lighthouse)
+            }
+            b"/Game/Maps/Campaign/District_01/Dungeons/WhalingMuseum/WhalingMuseum" => {
+                Some(Self::WhalingMuseum)
+            }
+            b"/Game/Maps/Campaign/District_01/Dungeons/MoviePalace/MoviePalace" => {
+                Some(Self::MoviePalace)
+            }
+            b"/Game/Maps/Campaign/District_01/Dungeons/Hospital/Hospital" => Some(Self::Hospital),
+            b"/Game/Maps/Campaign/District_02/Dungeons/Mine/Mine" => Some(Self::Mine),
+            b"/Game/Maps/Campaign/District_02/Dungeons/Church/Church" => Some(Self::Church),
+            b"/Game/Maps/Campaign/District_02/Dungeons/AevumFacility/AevumFacility" => {
+                Some(Self::AevumFacility)
+            }
+            b"/Game/Maps/Campaign/Bosses/HollowMan/Boss_HollowMan" => Some(Self::HollowManArena),
+            b"/Game/Maps/Campaign/Bosses/MissWhisper/Boss_MissWhisper" => {
+                Some(Self::MissWhisperArena)
+            }
+            b"/Game/Maps/Campaign/Bosses/BlackSun/Boss_BlackSun" => Some(Self::BlackSunArena),
+            _ if path.starts_with(b"/Game/Maps/Campaign/Nests/") => Some(Self::VampireNest),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mission {
+    WelcomeToRedfall,
+    LightInTheDark,
+    HollowMan,
+    MissWhisper,
+    CrossingOver,
+    BrightLights,
+    BlackSun,
+}
+
+impl Mission {
+    fn from_quest_id(quest_id: &[u8]) -> Option<Self> {
+        match quest_id {
+            b"MQ_D1_WelcomeToRedfall" => Some(Self::WelcomeToRedfall),
+            b"MQ_D1_LightInTheDark" => Some(Self::LightInTheDark),
+            b"MQ_D1_HollowMan" => Some(Self::HollowMan),
+            b"MQ_D1_MissWhisper" => Some(Self::MissWhisper),
+            b"MQ_D2_CrossingOver" => Some(Self::CrossingOver),
+            b"MQ_D2_BrightLights" => Some(Self::BrightLights),
+            b"MQ_D2_BlackSun" => Some(Self::BlackSun),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::WelcomeToRedfall => "Welcome to Redfall",
+            Self::LightInTheDark => "Light in the Dark",
+            Self::HollowMan => "The Hollow Man",
+            Self::MissWhisper => "Miss Whisper",
+            Self::CrossingOver => "Crossing Over",
+            Self::BrightLights => "Bright Lights",
+            Self::BlackSun => "The Black Sun",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Boss {
+    HollowMan,
+    MissWhisper,
+    BlackSun,
+}
+
+impl Boss {
+    fn from_id(boss_id: &[u8]) -> Option<Self> {
+        match boss_id {
+            b"VampireGod_HollowMan" => Some(Self::HollowMan),
+            b"VampireGod_MissWhisper" => Some(Self::MissWhisper),
+            b"VampireGod_BlackSun" => Some(Self::BlackSun),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Safehouse {
+    FireStation,
+    Marina,
+    TownHall,
+    Brewery,
+    Church,
+    Farmhouse,
+    LumberMill,
+    Cannery,
+}
+
+impl Safehouse {
+    fn from_id(safehouse_id: &[u8]) -> Option<Self> {
+        match safehouse_id {
+            b"Safehouse_D1_FireStation" => Some(Self::FireStation),
+            b"Safehouse_D1_Marina" => Some(Self::Marina),
+            b"Safehouse_D1_TownHall" => Some(Self::TownHall),
+            b"Safehouse_D1_Brewery" => Some(Self::Brewery),
+            b"Safehouse_D2_Church" => Some(Self::Church),
+            b"Safehouse_D2_Farmhouse" => Some(Self::Farmhouse),
+            b"Safehouse_D2_LumberMill" => Some(Self::LumberMill),
+            b"Safehouse_D2_Cannery" => Some(Self::Cannery),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Hero {
+    Layla,
+    Devinder,
+    Jacob,
+    Remi,
+}
+
+impl Hero {
+    fn from_class_name(class_name: &[u8]) -> Option<Self> {
+        match class_name {
+            b"BP_PlayerCharacter_Layla_C" => Some(Self::Layla),
+            b"BP_PlayerCharacter_Devinder_C" => Some(Self::Devinder),
+            b"BP_PlayerCharacter_Jacob_C" => Some(Self::Jacob),
+            b"BP_PlayerCharacter_Remi_C" => Some(Self::Remi),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Layla => "Layla",
+            Self::Devinder => "Devinder",
+            Self::Jacob => "Jacob",
+            Self::Remi => "Remi",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+    Insane,
+}
+
+impl Difficulty {
+    const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Easy),
+            1 => Some(Self::Normal),
+            2 => Some(Self::Hard),
+            3 => Some(Self::Insane),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+            Self::Insane => "Insane",
+        }
+    }
 }
 
 const PROCESS_NAMES: [&str; 1] = ["Redfall.exe"];
+const MAP_NAME_LEN: usize = 256;
+/// Maximum number of recognized sub-levels tracked at the same time
+const MAX_STREAMED_LEVELS: usize = 8;
+/// Game subsystems are kept by the GameInstance in its subsystem collection, which isn't
+/// exposed through reflection, so they're looked up in GUObjectArray by class instead
+const SUBSYSTEMS: [&str; 5] = [
+    "ArkQuestManager",
+    "ArkVampireGodManager",
+    "ArkDifficultyManager",
+    "ArkSaveGameManager",
+    "ArkSafehouseManager",
+];
+/// Number of GUObjectArray slots checked on each update while looking for game subsystems
+const OBJECTS_PER_UPDATE: u64 = 512;
+const MAX_LEVEL: u64 = 50;
+/// Distance the player needs to move from their spawn point for the practice start, in UE units (cm)
+const MOVEMENT_THRESHOLD: f32 = 50.0;
+/// Upper bound for a valid playtime, in seconds (100 years)
+const MAX_PLAY_TIME: f64 = 100.0 * 365.0 * 24.0 * 60.0 * 60.0;
@@ -6,11 +6,14 @@
 use core::{
     array,
     cell::RefCell,
+    fmt::Write,
     iter::{self, FusedIterator},
     mem::size_of,
+    ops::Range,
 };
 
-use bytemuck::CheckedBitPattern;
+use arrayvec::{ArrayString, ArrayVec};
+use bytemuck::{CheckedBitPattern, Pod, Zeroable};
 
 use asr::{
     file_format::pe, signature::Signature, string::ArrayCString, Address, PointerSize, Process,
@@ -18,15 +21,29 @@ use asr::{
 
 const CSTR: usize = 128;
 
+/// Maximum length of the outer chain of a `UObject`, used to avoid looping forever on invalid data
+const MAX_OUTER_CHAIN: usize = 32;
+
+/// Number of elements stored in each chunk of `GUObjectArray`
+const NUM_ELEMENTS_PER_CHUNK: u64 = 64 * 1024;
+
+/// Object flag set on class default objects
+const RF_CLASS_DEFAULT_OBJECT: u32 = 0x10;
+
 /// Represents access to a Unreal Engine game.
 ///
 /// This struct gives immediate access to 2 important structs present in every UE game:
 /// - GEngine: a static object that persists throughout the process' lifetime
 /// - GWorld: a pointer to the currently loaded UWorld object
+///
+/// It also gives access to `GUObjectArray`, the global list of every live `UObject`,
+/// which allows finding objects not directly reachable from GEngine.
 pub struct Module {
     pointer_size: PointerSize,
     offsets: &'static Offsets,
     g_engine: Address,
+    g_world: Option<Address>,
+    g_uobject_array: Option<Address>,
     fname_base: Address,
 }
 
@@ -35,7 +52,6 @@ impl Module {
     /// function to work.
     pub fn attach(process: &Process, main_module_address: Address) -> Option<Self> {
         let pointer_size = pe::MachineType::read(process, main_module_address)?.pointer_size()?;
-        let offsets = Offsets::new();
         let module_size = pe::read_size_of_image(process, main_module_address)? as u64;
         let module_range = (main_module_address, module_size);
 
@@ -57,6 +73,45 @@ impl Module {
             }
         };
 
+        // The world can also be reached through GEngine, so failing to find GWorld
+        // shouldn't prevent attaching to the game.
+        let g_world = {
+            const GWORLD: (Signature<15>, u32) = (
+                Signature::new("48 8B 1D ?? ?? ?? ?? 48 85 DB 74 ?? 41 B0 01"),
+                3,
+            );
+
+            GWORLD
+                .0
+                .scan_process_range(process, module_range)
+                .and_then(|addr| {
+                    let addr = addr + GWORLD.1;
+                    Some(addr + 0x4 + process.read::<i32>(addr).ok()?)
+                })
+        };
+
+        // The signatures point to the code reading the chunk table of `FChunkedFixedUObjectArray`,
+        // which is the `ObjObjects` field inside `GUObjectArray`.
+        // This is only needed to find objects not reachable from GEngine, so failing to find it
+        // shouldn't prevent attaching to the game.
+        let g_uobject_array = {
+            const GUOBJECTARRAY: &[(Signature<15>, u8)] = &[
+                (
+                    Signature::new("48 8B 05 ?? ?? ?? ?? 48 8B 0C C8 48 8D 04 D1"),
+                    3,
+                ),
+                (
+                    Signature::new("48 8B 05 ?? ?? ?? ?? 48 8B 0C C8 4C 8D 04 D1"),
+                    3,
+                ),
+            ];
+
+            GUOBJECTARRAY.iter().find_map(|(sig, offset)| {
+                let addr = sig.scan_process_range(process, module_range)? + *offset;
+                Some(addr + 0x4 + process.read::<i32>(addr).ok()?)
+            })
+        };
+
         let fname_base = {
             const FNAME_POOL: &[(Signature<13>, u8)] = &[
                 (Signature::new("74 09 48 8D 15 ?? ?? ?? ?? EB 16 ?? ??"), 5),
@@ -70,11 +125,67 @@ impl Module {
             addr + 0x4 + process.read::<i32>(addr).ok()?
         };
 
-        Some(Self {
+        let mut module = Self {
             pointer_size,
-            offsets,
+            offsets: &Offsets::UE_4_25,
             g_engine,
+            g_world,
+            g_uobject_array,
             fname_base,
+        };
+        module.offsets = module.detect_offsets(process)?;
+        Some(module)
+    }
+
+    /// Detects how reflected properties are stored in memory, by looking at the class of GEngine.
+    ///
+    /// Since UE 4.25, properties are `FProperty` objects linked from the `ChildProperties`
+    /// field of the class, while older versions use `UProperty` objects, which inherit from `UObject`.
+    /// In the older layout, the same memory holds the size and alignment of the class instead.
+    fn detect_offsets(&self, process: &Process) -> Option<&'static Offsets> {
+        const USTRUCT_CHILD_PROPERTIES: u64 = 0x50;
+
+        // The layout of `UObject` is the same across versions, so the class can be read
+        // with any set of offsets. GEngine might still be null while the game is booting.
+        let engine = UObject {
+            object: process
+                .read_pointer(self.g_engine, self.pointer_size)
+                .ok()?,
+        };
+        let child_properties = process
+            .read_pointer(
+                engine.get_uclass(process, self)?.class + USTRUCT_CHILD_PROPERTIES,
+                self.pointer_size,
+            )
+            .ok()?;
+
+        // A class with no properties of its own has no `ChildProperties`,
+        // while the size of a class can never be zero
+        if child_properties == Address::NULL {
+            return Some(&Offsets::UE_4_25);
+        }
+
+        let is_fproperty = process
+            .read_pointer(
+                child_properties + Offsets::UE_4_25.uproperty_class,
+                self.pointer_size,
+            )
+            .ok()
+            .and_then(|field_class| {
+                process
+                    .read::<FName>(field_class + Offsets::UE_4_25.uproperty_class_fname)
+                    .ok()
+            })
+            .and_then(|name| name.resolve::<CSTR>(process, self))
+            .is_some_and(|name| {
+                name.validate_utf8()
+                    .is_ok_and(|name| name.ends_with("Property"))
+            });
+
+        Some(if is_fproperty {
+            &Offsets::UE_4_25
+        } else {
+            &Offsets::UE_4_24
         })
     }
 
@@ -83,12 +194,166 @@ impl Module {
         self.g_engine
     }
 
+    /// Returns the memory pointer to GWorld, if it has been found
+    pub const fn g_world(&self) -> Option<Address> {
+        self.g_world
+    }
+
+    /// Returns the number of slots in use in `GUObjectArray`, including the ones
+    /// left empty by garbage collected objects.
+    /// If `GUObjectArray` couldn't be found, the array is considered empty.
+    pub fn num_objects(&self, process: &Process) -> u64 {
+        self.g_uobject_array
+            .and_then(|g_uobject_array| {
+                process
+                    .read::<i32>(g_uobject_array + self.offsets.fuobjectarray_num_elements)
+                    .ok()
+            })
+            .unwrap_or_default()
+            .max(0) as u64
+    }
+
+    /// Iterates over every live `UObject` stored in `GUObjectArray`.
+    /// If `GUObjectArray` couldn't be found, the iterator is empty.
+    pub fn objects<'a>(
+        &'a self,
+        process: &'a Process,
+    ) -> impl FusedIterator<Item = FUObjectItem> + 'a {
+        self.objects_in(process, 0..u64::MAX)
+    }
+
+    /// Iterates over the live `UObject`s stored in the specified range of slots of `GUObjectArray`
+    pub fn objects_in<'a>(
+        &'a self,
+        process: &'a Process,
+        range: Range<u64>,
+    ) -> impl FusedIterator<Item = FUObjectItem> + 'a {
+        let chunks = self
+            .g_uobject_array
+            .and_then(|g_uobject_array| {
+                process
+                    .read_pointer(g_uobject_array, self.pointer_size)
+                    .ok()
+            })
+            .unwrap_or(Address::NULL);
+        let end = range.end.min(self.num_objects(process));
+        let start = range.start.min(end);
+
+        // The chunk pointer is read only once, and then shared by every item in the chunk
+        (start / NUM_ELEMENTS_PER_CHUNK..end.div_ceil(NUM_ELEMENTS_PER_CHUNK))
+            .filter_map(move |chunk_index| {
+                let chunk = process
+                    .read_pointer(
+                        chunks + self.size_of_ptr().wrapping_mul(chunk_index),
+                        self.pointer_size,
+                    )
+                    .ok()?;
+                let first = chunk_index * NUM_ELEMENTS_PER_CHUNK;
+                Some((
+                    chunk,
+                    start.max(first) - first..(end - first).min(NUM_ELEMENTS_PER_CHUNK),
+                ))
+            })
+            .flat_map(move |(chunk, indices)| {
+                indices.filter_map(move |index| {
+                    let item = chunk + (self.offsets.fuobjectitem_size as u64).wrapping_mul(index);
+
+                    match process.read_pointer(item, self.pointer_size) {
+                        Ok(Address::NULL) | Err(_) => None,
+                        Ok(object) => Some(FUObjectItem {
+                            item,
+                            object: UObject { object },
+                        }),
+                    }
+                })
+            })
+            .fuse()
+    }
+
+    /// Looks for the first instance of the specified class in `GUObjectArray`.
+    /// Class default objects are ignored.
+    #[allow(dead_code)]
+    pub fn find_object_by_class(
+        &self,
+        process: &Process,
+        class_name: &str,
+    ) -> Option<FUObjectItem> {
+        self.objects(process)
+            .find(|item| item.object.is_instance_of(process, self, class_name))
+    }
+
+    /// Looks for the object with the specified path in `GUObjectArray`,
+    /// e.g. `/Game/Maps/Campaign/FrontEnd/FrontEnd.FrontEnd:PersistentLevel`
+    #[allow(dead_code)]
+    pub fn find_object_by_path(&self, process: &Process, path: &str) -> Option<FUObjectItem> {
+        self.objects(process)
+            .find(|item| item.object.matches_path(process, self, path))
+    }
+
+    /// Same as `find_object_by_class`, but looks for several classes at once, and only
+    /// in the specified range of slots of `GUObjectArray`. Scanning the whole array is expensive,
+    /// so this allows spreading the search over several calls.
+    pub fn find_objects_by_class<const N: usize>(
+        &self,
+        process: &Process,
+        class_names: [&str; N],
+        range: Range<u64>,
+    ) -> [Option<FUObjectItem>; N] {
+        let mut found = [None; N];
+
+        for item in self.objects_in(process, range) {
+            if found.iter().all(Option::is_some) {
+                break;
+            }
+            if item.object.is_class_default_object(process, self) {
+                continue;
+            }
+            let Some(name) = item.object.get_class_name::<CSTR>(process, self) else {
+                continue;
+            };
+            if let Some(index) = class_names
+                .iter()
+                .position(|&class_name| name.as_str() == class_name)
+            {
+                found[index].get_or_insert(item);
+            }
+        }
+
+        found
+    }
+
     #[inline]
     const fn size_of_ptr(&self) -> u64 {
         self.pointer_size as u64
     }
 }
 
+/// An entry of `GUObjectArray`, containing a pointer to a live `UObject`
+#[derive(Copy, Clone)]
+pub struct FUObjectItem {
+    item: Address,
+    object: UObject,
+}
+
+impl FUObjectItem {
+    /// Returns the address of the entry. The entry begins with the pointer to its `UObject`,
+    /// so this address can be used as the base address of an `UnrealPointer`.
+    pub const fn address(&self) -> Address {
+        self.item
+    }
+
+    /// Returns `true` if the entry still holds an instance of the specified class.
+    ///
+    /// Entries are reused once the object they hold gets garbage collected,
+    /// so an entry might end up holding an unrelated object.
+    pub fn holds_instance_of(&self, process: &Process, module: &Module, class_name: &str) -> bool {
+        match process.read_pointer(self.item, module.pointer_size) {
+            Ok(Address::NULL) | Err(_) => false,
+            Ok(object) => UObject { object }.is_instance_of(process, module, class_name),
+        }
+    }
+}
+
 /// An `UObject` is the base class of every Unreal Engine object,
 /// from which every other class in the UE engine inherits from.
 ///
@@ -99,12 +364,150 @@ impl Module {
 // Docs:
 // - https://docs.unrealengine.com/4.27/en-US/API/Runtime/CoreUObject/UObject/UObject/
 // - https://gist.github.com/apple1417/b23f91f7a9e3b834d6d052d35a0010ff#object-structure
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct UObject {
     object: Address,
 }
 
 impl UObject {
+    /// Returns the name of the current `UObject`
+    pub fn get_name<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayString<N>> {
+        process
+            .read::<FName>(self.object + module.offsets.uobject_fname)
+            .ok()?
+            .to_string(process, module)
+    }
+
+    /// Returns the object the current `UObject` is contained in, if any
+    fn get_outer(&self, process: &Process, module: &Module) -> Option<UObject> {
+        match process.read_pointer(
+            self.object + module.offsets.uobject_outer,
+            module.pointer_size,
+        ) {
+            Ok(Address::NULL) | Err(_) => None,
+            Ok(val) => Some(UObject { object: val }),
+        }
+    }
+
+    /// Returns `true` if the current `UObject` is the default object of its class
+    fn is_class_default_object(&self, process: &Process, module: &Module) -> bool {
+        process
+            .read::<u32>(self.object + module.offsets.uobject_flags)
+            .is_ok_and(|flags| flags & RF_CLASS_DEFAULT_OBJECT != 0)
+    }
+
+    /// Returns `true` if the current `UObject` is an instance of the class with the specified name.
+    /// Class default objects are not considered instances of their class.
+    fn is_instance_of(&self, process: &Process, module: &Module, class_name: &str) -> bool {
+        !self.is_class_default_object(process, module)
+            && self
+                .get_class_name::<CSTR>(process, module)
+                .is_some_and(|name| name.as_str() == class_name)
+    }
+
+    /// Checks whether the path of the current `UObject` matches the specified one.
+    ///
+    /// Each segment of the path is compared, starting from the end, with the names
+    /// of the objects in the outer chain. The first segment needs to be the package.
+    fn matches_path(&self, process: &Process, module: &Module, path: &str) -> bool {
+        let mut object = Some(*self);
+
+        for segment in path.rsplit(['.', ':']) {
+            let Some(current) = object else {
+                return false;
+            };
+            if !process
+                .read::<FName>(current.object + module.offsets.uobject_fname)
+                .is_ok_and(|name| name.matches(process, module, segment))
+            {
+                return false;
+            }
+            object = current.get_outer(process, module);
+        }
+
+        object.is_none()
+    }
+
+    /// Returns the chain of outer objects, starting from the current `UObject`
+    /// and ending with the package it belongs to
+    fn get_outer_chain(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> ArrayVec<UObject, MAX_OUTER_CHAIN> {
+        iter::successors(Some(*self), |object| object.get_outer(process, module))
+            .take(MAX_OUTER_CHAIN)
+            .collect()
+    }
+
+    /// Returns `true` if the current `UObject` is a package
+    fn is_package(&self, process: &Process, module: &Module) -> bool {
+        self.get_class_name::<CSTR>(process, module)
+            .is_some_and(|name| name.as_str() == "Package")
+    }
+
+    /// Returns the name of the package the current `UObject` belongs to.
+    ///
+    /// The package is the outermost object in the outer chain, and its name
+    /// is the path of the asset the object has been loaded from.
+    pub fn get_package_name<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayString<N>> {
+        self.get_outer_chain(process, module)
+            .last()?
+            .get_name(process, module)
+    }
+
+    /// Returns the full path of the current `UObject`, built from the names of the objects
+    /// in its outer chain, e.g. `/Game/Maps/Campaign/FrontEnd/FrontEnd.FrontEnd:PersistentLevel`
+    ///
+    /// As in UE, subobjects whose outer is not a package, but belongs directly to one,
+    /// are separated by `:` instead of `.`.
+    pub fn get_full_path<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayString<N>> {
+        let chain = self.get_outer_chain(process, module);
+        let (package, objects) = chain.split_last()?;
+
+        let mut path = package.get_name::<N>(process, module)?;
+        for (i, object) in objects.iter().enumerate().rev() {
+            let outer = chain[i + 1];
+            let delimiter = match chain.get(i + 2) {
+                Some(outer_outer)
+                    if !outer.is_package(process, module)
+                        && outer_outer.is_package(process, module) =>
+                {
+                    ':'
+                }
+                _ => '.',
+            };
+            path.try_push(delimiter).ok()?;
+            path.try_push_str(&object.get_name::<CSTR>(process, module)?)
+                .ok()?;
+        }
+        Some(path)
+    }
+
+    /// Returns the name of the class the current `UObject` is an instance of
+    pub fn get_class_name<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayString<N>> {
+        UObject {
+            object: self.get_uclass(process, module)?.class,
+        }
+        .get_name(process, module)
+    }
+
     /// Returns the underlying class definition for the current `UObject`
     fn get_uclass(&self, process: &Process, module: &Module) -> Option<UClass> {
         match process.read_pointer(
@@ -116,16 +519,15 @@ impl UObject {
         }
     }
 
-    /// Tries to find a field with the specified name in the current UObject and returns
-    /// the offset of the field from the start of an instance of the class.
-    pub fn get_field_offset(
+    /// Tries to find the definition of a field with the specified name in the current UObject
+    fn get_property(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
-    ) -> Option<u32> {
+    ) -> Option<UProperty> {
         self.get_uclass(process, module)?
-            .get_field_offset(process, module, field_name)
+            .find_property(process, module, field_name)
     }
 }
 
@@ -205,21 +607,19 @@ impl UClass {
         .fuse()
     }
 
-    /// Returns the offset for the specified named property.
+    /// Returns the property with the specified name.
     /// Returns `None` on case of failure.
-    fn get_field_offset(
+    fn find_property(
         &self,
         process: &Process,
         module: &Module,
         field_name: &str,
-    ) -> Option<u32> {
-        self.properties(process, module)
-            .find(|field| {
-                field
-                    .get_fname::<CSTR>(process, module)
-                    .is_some_and(|name| name.matches(field_name))
-            })?
-            .get_offset(process, module)
+    ) -> Option<UProperty> {
+        self.properties(process, module).find(|field| {
+            field
+                .get_fname::<CSTR>(process, module)
+                .is_some_and(|name| name.matches(field_name))
+        })
     }
 }
 
@@ -238,9 +638,67 @@ impl UProperty {
         process: &Process,
         module: &Module,
     ) -> Option<ArrayCString<N>> {
-        let [name_offset, chunk_offset] = process
-            .read::<[u16; 2]>(self.property + module.offsets.uproperty_fname)
+        process
+            .read::<FName>(self.property + module.offsets.uproperty_fname)
+            .ok()?
+            .resolve(process, module)
+    }
+
+    fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
+        process
+            .read(self.property + module.offsets.uproperty_offset_internal)
+            .ok()
+    }
+
+    /// If the current property is a `BoolProperty`, returns the offset of the byte holding
+    /// its value, relative to the property's offset, and the mask of the bit holding it.
+    ///
+    /// Native booleans are usually declared as bitfields, sharing the same byte with other flags.
+    // Source: https://github.com/bl-sdk/unrealsdk/blob/master/src/unrealsdk/unreal/classes/properties/uboolproperty.h
+    fn get_bool_mask(&self, process: &Process, module: &Module) -> Option<(u8, u8)> {
+        let class = process
+            .read_pointer(
+                self.property + module.offsets.uproperty_class,
+                module.pointer_size,
+            )
             .ok()?;
+        let class_name = process
+            .read::<FName>(class + module.offsets.uproperty_class_fname)
+            .ok()?
+            .resolve::<CSTR>(process, module)?;
+        if !class_name.matches("BoolProperty") {
+            return None;
+        }
+
+        let byte_offset = process
+            .read(self.property + module.offsets.uboolproperty_byte_offset)
+            .ok()?;
+        let field_mask = process
+            .read(self.property + module.offsets.uboolproperty_field_mask)
+            .ok()?;
+        Some((byte_offset, field_mask))
+    }
+}
+
+/// A `FName` is the compact representation UE uses for most of its names:
+/// an index into the global name pool, plus an instance number.
+// Source: https://github.com/bl-sdk/unrealsdk/blob/master/src/unrealsdk/unreal/structs/fname.h
+#[derive(Copy, Clone, Default, Pod, Zeroable)]
+#[repr(C)]
+pub struct FName {
+    index: u32,
+    number: u32,
+}
+
+impl FName {
+    /// Looks up the current `FName` in the name pool and returns the string it represents
+    pub fn resolve<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayCString<N>> {
+        let name_offset = self.index & 0xFFFF;
+        let chunk_offset = self.index >> 16;
 
         let addr = process
             .read_pointer(
@@ -250,11 +708,14 @@ impl UProperty {
             .ok()?
             + (name_offset as u64).wrapping_mul(size_of::<u16>() as u64);
 
-        let string_size = process
-            .read::<u16>(addr)
-            .ok()?
-            .checked_shr(6)
-            .unwrap_or_default() as usize;
+        // The entry header stores the length of the string in the upper 10 bits,
+        // while the lowest bit signals whether the string is stored as UTF-16.
+        // Wide entries can't be represented as C strings, so they're not supported.
+        let header = process.read::<u16>(addr).ok()?;
+        if header & 1 != 0 {
+            return None;
+        }
+        let string_size = header.checked_shr(6).unwrap_or_default() as usize;
 
         let mut string = process
             .read::<ArrayCString<N>>(addr + size_of::<u16>() as u64)
@@ -264,10 +725,133 @@ impl UProperty {
         Some(string)
     }
 
-    fn get_offset(&self, process: &Process, module: &Module) -> Option<u32> {
+    /// Returns the string represented by the `FName`, including the instance number
+    pub fn to_string<const N: usize>(
+        &self,
+        process: &Process,
+        module: &Module,
+    ) -> Option<ArrayString<N>> {
+        let name = self.resolve::<CSTR>(process, module)?;
+        let mut string = ArrayString::new();
+        string.try_push_str(name.validate_utf8().ok()?).ok()?;
+        if self.number != 0 {
+            write!(string, "_{}", self.number - 1).ok()?;
+        }
+        Some(string)
+    }
+
+    /// Checks whether the `FName` matches the specified text.
+    ///
+    /// Unlike `resolve`, this also takes into account the instance number,
+    /// which is represented as a `_N` suffix, with `N` being the number minus one.
+    fn matches(&self, process: &Process, module: &Module, text: &str) -> bool {
+        let Some(name) = self.resolve::<CSTR>(process, module) else {
+            return false;
+        };
+
+        match self.number {
+            0 => name.matches(text),
+            number => text.rsplit_once('_').is_some_and(|(base, suffix)| {
+                name.matches(base) && suffix.parse::<u32>().is_ok_and(|val| val == number - 1)
+            }),
+        }
+    }
+}
+
+/// A `TArray` is UE's dynamically sized array. In memory it's represented by
+/// a pointer to the data, followed by the number of used and allocated elements.
+#[derive(Copy, Clone, Default, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+pub struct TArray {
+    data: u64,
+    count: i32,
+    max: i32,
+}
+
+impl TArray {
+    /// Returns the number of elements currently stored in the array
+    pub const fn len(&self) -> usize {
+        if self.count < 0 || self.count > self.max {
+            0
+        } else {
+            self.count as usize
+        }
+    }
+
+    /// Returns the address of the element stored at the specified index.
+    /// For arrays of `UObject` pointers, it can be used as the base address of an `UnrealPointer`.
+    pub fn get_address<T>(&self, index: usize) -> Option<Address> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(Address::new(self.data) + (index as u64).wrapping_mul(size_of::<T>() as u64))
+    }
+
+    /// Reads the element stored at the specified index
+    pub fn get<T: CheckedBitPattern>(&self, process: &Process, index: usize) -> Option<T> {
+        process.read(self.get_address::<T>(index)?).ok()
+    }
+
+    /// Reads the `UObject` pointer stored at the specified index
+    pub fn get_uobject(&self, process: &Process, index: usize) -> Option<UObject> {
+        match self.get::<u64>(process, index).map(Address::new)? {
+            Address::NULL => None,
+            object => Some(UObject { object }),
+        }
+    }
+
+    /// Reads the last element stored in the array
+    pub fn last<T: CheckedBitPattern>(&self, process: &Process) -> Option<T> {
+        self.get(process, self.len().checked_sub(1)?)
+    }
+}
+
+/// A `FString` is UE's string type. In memory it's represented by a `TArray`
+/// of UTF-16 characters, which includes the null terminator in its count.
+#[derive(Copy, Clone, Default, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct FString(TArray);
+
+impl FString {
+    /// Reads the string and converts it to UTF-8.
+    ///
+    /// Returns `None` if the string is not valid UTF-16 or doesn't fit in `N` bytes.
+    pub fn read<const N: usize>(&self, process: &Process) -> Option<ArrayString<N>> {
+        let len = self.0.len().saturating_sub(1);
+
+        // Every UTF-16 code unit takes at least one byte once converted to UTF-8
+        let mut buf = [0u16; N];
+        let buf = buf.get_mut(..len)?;
         process
-            .read(self.property + module.offsets.uproperty_offset_internal)
-            .ok()
+            .read_into_slice(Address::new(self.0.data), buf)
+            .ok()?;
+
+        let mut string = ArrayString::new();
+        for c in char::decode_utf16(buf.iter().copied()) {
+            string.try_push(c.ok()?).ok()?;
+        }
+        Some(string)
+    }
+}
+
+/// A `FURL` holds the URL a `UWorld` has been loaded from,
+/// including the path of the map being played.
+// Source: https://docs.unrealengine.com/4.27/en-US/API/Runtime/Engine/Engine/FURL/
+#[derive(Copy, Clone, Default, Pod, Zeroable)]
+#[repr(C)]
+pub struct FUrl {
+    _protocol: FString,
+    _host: FString,
+    _port: i32,
+    _valid: i32,
+    map: FString,
+}
+
+impl FUrl {
+    /// Returns the path of the map referred by the URL
+    pub const fn map(&self) -> FString {
+        self.map
     }
 }
 
@@ -284,6 +868,7 @@ pub struct UnrealPointer<const CAP: usize> {
 struct UnrealPointerCache<const CAP: usize> {
     offsets: [u64; CAP],
     resolved_offsets: usize,
+    field_mask: u8,
 }
 
 impl<const CAP: usize> UnrealPointer<CAP> {
@@ -302,6 +887,7 @@ impl<const CAP: usize> UnrealPointer<CAP> {
         let cache = RefCell::new(UnrealPointerCache {
             offsets: [u64::default(); CAP],
             resolved_offsets: usize::default(),
+            field_mask: u8::MAX,
         });
 
         Self {
@@ -347,12 +933,24 @@ impl<const CAP: usize> UnrealPointer<CAP> {
                 _ => self.fields[i].parse().ok(),
             };
 
-            let current_offset = match offset_from_string {
-                Some(offset) => offset as u64,
-                _ => current_uobject.get_field_offset(process, module, self.fields[i])? as u64,
+            // Boolean properties point directly to the byte holding their bit,
+            // and keep track of the bit itself so it can be masked when dereferencing
+            let (current_offset, field_mask) = match offset_from_string {
+                Some(offset) => (offset as u64, u8::MAX),
+                _ => {
+                    let property = current_uobject.get_property(process, module, self.fields[i])?;
+                    let offset = property.get_offset(process, module)? as u64;
+                    match property.get_bool_mask(process, module) {
+                        Some((byte_offset, field_mask)) => {
+                            (offset + byte_offset as u64, field_mask)
+                        }
+                        _ => (offset, u8::MAX),
+                    }
+                }
             };
 
             cache.offsets[i] = current_offset;
+            cache.field_mask = field_mask;
             cache.resolved_offsets += 1;
 
             current_uobject = UObject {
@@ -364,6 +962,21 @@ impl<const CAP: usize> UnrealPointer<CAP> {
         Some(())
     }
 
+    /// Dereferences the pointer path, returning the `UObject` stored at the final memory address
+    pub fn deref_uobject(&self, process: &Process, module: &Module) -> Option<UObject> {
+        match self.deref::<u64>(process, module).map(Address::new)? {
+            Address::NULL => None,
+            object => Some(UObject { object }),
+        }
+    }
+
+    /// Dereferences the pointer path to a boolean, taking into account the bit the value is stored in,
+    /// in case the final field is a bitfield
+    pub fn deref_bool(&self, process: &Process, module: &Module) -> Option<bool> {
+        let value = self.deref::<u8>(process, module)?;
+        Some(value & self.cache.borrow().field_mask != 0)
+    }
+
     /// Dereferences the pointer path, returning the value stored at the final memory address
     pub fn deref<T: CheckedBitPattern>(&self, process: &Process, module: &Module) -> Option<T> {
         self.find_offsets(process, module)?;
@@ -380,24 +993,64 @@ impl<const CAP: usize> UnrealPointer<CAP> {
     }
 }
 
+/// Memory offsets of the engine structs, which depend on the UE version.
+///
+/// The `uproperty_*` offsets refer to `FProperty` on UE 4.25+ and to `UProperty` on older versions.
 struct Offsets {
+    fuobjectarray_num_elements: u8,
+    fuobjectitem_size: u8,
+    uobject_flags: u8,
     uobject_class: u8,
+    uobject_fname: u8,
+    uobject_outer: u8,
     uclass_super_field: u8,
     uclass_property_link: u8,
+    uproperty_class: u8,
+    uproperty_class_fname: u8,
     uproperty_fname: u8,
     uproperty_offset_internal: u8,
     uproperty_property_link_next: u8,
+    uboolproperty_byte_offset: u8,
+    uboolproperty_field_mask: u8,
 }
 
 impl Offsets {
-    const fn new() -> &'static Self {
-        &Self {
-            uobject_class: 0x10,
-            uclass_super_field: 0x40,
-            uclass_property_link: 0x50,
-            uproperty_fname: 0x28,
-            uproperty_offset_internal: 0x4C,
-            uproperty_property_link_next: 0x58,
-        }
-    }
+    /// UE 4.25+, with properties stored as `FField`s
+    // Source: https://github.com/bl-sdk/unrealsdk/blob/master/src/unrealsdk/unreal/classes/ufield.h
+    const UE_4_25: Self = Self {
+        fuobjectarray_num_elements: 0x14,
+        fuobjectitem_size: 0x18,
+        uobject_flags: 0x8,
+        uobject_class: 0x10,
+        uobject_fname: 0x18,
+        uobject_outer: 0x20,
+        uclass_super_field: 0x40,
+        uclass_property_link: 0x70,
+        uproperty_class: 0x8,
+        uproperty_class_fname: 0x0,
+        uproperty_fname: 0x28,
+        uproperty_offset_internal: 0x4C,
+        uproperty_property_link_next: 0x58,
+        uboolproperty_byte_offset: 0x79,
+        uboolproperty_field_mask: 0x7B,
+    };
+
+    /// UE 4.24 and older, with properties stored as `UObject`s
+    const UE_4_24: Self = Self {
+        fuobjectarray_num_elements: 0x14,
+        fuobjectitem_size: 0x18,
+        uobject_flags: 0x8,
+        uobject_class: 0x10,
+        uobject_fname: 0x18,
+        uobject_outer: 0x20,
+        uclass_super_field: 0x40,
+        uclass_property_link: 0x68,
+        uproperty_class: 0x10,
+        uproperty_class_fname: 0x18,
+        uproperty_fname: 0x18,
+        uproperty_offset_internal: 0x44,
+        uproperty_property_link_next: 0x50,
+        uboolproperty_byte_offset: 0x71,
+        uboolproperty_field_mask: 0x73,
+    };
 }